@@ -0,0 +1,67 @@
+//! Walks through standing up a verifier and its access controller from
+//! scratch: initialize both programs, grant a reporting address access,
+//! register a DON config, and submit a test report.
+//!
+//! Run with `cargo run --example deploy`. Account creation (funding and
+//! assigning the verifier/access controller data accounts to their program
+//! ids) happens ahead of time via the System program and isn't part of this
+//! client's scope, so it is left out here.
+
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_verifier_admin::{AccessControllerClient, VerifierAdminClient};
+
+fn main() {
+    let rpc_url = "https://api.devnet.solana.com";
+    let verifier_program_id = Pubkey::new_unique();
+    let verifier_data_account = Pubkey::new_unique();
+    let access_controller_program_id = Pubkey::new_unique();
+    let access_controller_account = Pubkey::new_unique();
+    let reporting_address = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let verifier = VerifierAdminClient::new(
+        rpc_url,
+        verifier_program_id,
+        verifier_data_account,
+        Keypair::try_from(payer.to_bytes().as_slice()).expect("valid keypair bytes"),
+    );
+    let access_controller = AccessControllerClient::new(
+        rpc_url,
+        access_controller_program_id,
+        access_controller_account,
+        payer,
+    );
+
+    let signature = access_controller
+        .send_transaction(&[access_controller.get_instruction_for_initialize()], &[])
+        .unwrap_or_else(|e| panic!("failed to initialize access controller: {e}"));
+    println!("initialized access controller: {signature}");
+
+    let signature = verifier
+        .send_transaction(&[verifier.get_instruction_for_initialize()], &[])
+        .unwrap_or_else(|e| panic!("failed to initialize verifier: {e}"));
+    println!("initialized verifier: {signature}");
+
+    let signature = access_controller
+        .batch_add_access(&[reporting_address])
+        .unwrap_or_else(|e| panic!("failed to grant access to {reporting_address}: {e}"))
+        .remove(0);
+    println!("granted access to {reporting_address}: {signature}");
+
+    let signers: Vec<[u8; 20]> = vec![[1u8; 20], [2u8; 20], [3u8; 20], [4u8; 20]];
+    let config_id = [7u8; 32];
+    let signature = verifier
+        .set_config(config_id, signers, 1)
+        .unwrap_or_else(|e| panic!("failed to set config: {e}"));
+    println!("registered config: {signature}");
+
+    let cache = verifier
+        .warm_up()
+        .unwrap_or_else(|e| panic!("failed to warm up verifier cache: {e}"));
+    let test_report = b"test report payload".to_vec();
+    let signature = verifier
+        .verify_cached(test_report, &cache)
+        .unwrap_or_else(|e| panic!("failed to verify test report: {e}"));
+    println!("verified test report: {signature}");
+}
@@ -0,0 +1,51 @@
+//! Demonstrates a zero-downtime signer rotation: register a new DON config
+//! alongside the existing one and verify a test report against it. Keeping
+//! both configs active during the rollout means in-flight reports signed
+//! under the old signer set keep verifying until every downstream consumer
+//! has cut over. The old config is not retired by this example; it stays
+//! active when the example exits.
+//!
+//! Run with `cargo run --example rotate_signers`.
+
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_verifier_admin::VerifierAdminClient;
+
+fn main() {
+    let verifier = VerifierAdminClient::new(
+        "https://api.devnet.solana.com",
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Keypair::new(),
+    );
+
+    let account = verifier
+        .get_verifier_account()
+        .unwrap_or_else(|e| panic!("failed to read current configs: {e}"));
+    println!("{} config(s) currently registered", account.configs.len());
+
+    // The rotation plan: carry over the same fault-tolerance bound `f`, but
+    // move to a freshly generated signer set.
+    let f = account.configs.last().map_or(1, |config| config.f);
+    let new_signers: Vec<[u8; 20]> = vec![[10u8; 20], [11u8; 20], [12u8; 20], [13u8; 20]];
+    let new_config_id = [9u8; 32];
+
+    let signature = verifier
+        .set_config(new_config_id, new_signers, f)
+        .unwrap_or_else(|e| panic!("failed to register rotated config: {e}"));
+    println!("registered rotated config: {signature}");
+
+    let cache = verifier
+        .warm_up()
+        .unwrap_or_else(|e| panic!("failed to warm up verifier cache: {e}"));
+    let test_report = b"rotation smoke-test report".to_vec();
+    let signature = verifier
+        .verify_cached(test_report, &cache)
+        .unwrap_or_else(|e| panic!("failed to verify against rotated config: {e}"));
+    println!("verified test report against rotated config: {signature}");
+
+    // Retiring the previous config once consumers have cut over isn't yet
+    // exposed by this client (there's no instruction for deactivating a
+    // config), so the old signer set stays active until that support lands.
+    println!("rotation complete; old config left active pending deactivation support");
+}
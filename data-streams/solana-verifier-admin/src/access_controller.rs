@@ -0,0 +1,448 @@
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use borsh::BorshDeserialize;
+use solana_account_decoder_client_types::UiDataSliceConfig;
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_client::rpc_config::RpcAccountInfoConfig;
+use solana_client::rpc_response::{Response, RpcSimulateTransactionResult};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+use solana_transaction_status_client_types::UiTransactionEncoding;
+
+use crate::error::{ClientError, DecodeError};
+use crate::instructions::{AccessControllerInstruction, DecodedAccessControllerInstruction};
+use crate::state::{AccessControllerAccount, StateIssue, MAX_ACCESS_LIST_ADDRESSES};
+
+/// Derives a websocket URL from an RPC client's HTTP(S) URL, by swapping the
+/// scheme, the way Solana's JSON-RPC and PubSub endpoints are conventionally
+/// paired.
+fn websocket_url(rpc_url: &str) -> String {
+    if let Some(rest) = rpc_url.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else {
+        rpc_url.to_string()
+    }
+}
+
+/// Admin client for the Chainlink Data Streams Access Controller program on
+/// Solana.
+///
+/// Manages the access list that gates who may submit reports to a verifier
+/// program.
+pub struct AccessControllerClient {
+    pub(crate) rpc_client: RpcClient,
+    pub(crate) program_id: Pubkey,
+    pub(crate) access_controller_account: Pubkey,
+    pub(crate) payer: Keypair,
+}
+
+impl AccessControllerClient {
+    /// Creates a client targeting `access_controller_account` on the
+    /// cluster at `rpc_url`, signing transactions with `payer`.
+    pub fn new(
+        rpc_url: &str,
+        program_id: Pubkey,
+        access_controller_account: Pubkey,
+        payer: Keypair,
+    ) -> Self {
+        Self {
+            rpc_client: RpcClient::new_with_commitment(
+                rpc_url.to_string(),
+                CommitmentConfig::confirmed(),
+            ),
+            program_id,
+            access_controller_account,
+            payer,
+        }
+    }
+
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    pub fn access_controller_account(&self) -> Pubkey {
+        self.access_controller_account
+    }
+
+    pub fn payer(&self) -> &Keypair {
+        &self.payer
+    }
+
+    /// Signs an arbitrary message with the payer keypair. Useful for
+    /// off-chain attestation of admin intent alongside on-chain
+    /// transactions.
+    pub fn sign_message(&self, message: &[u8]) -> Signature {
+        self.payer.sign_message(message)
+    }
+
+    /// Decodes raw instruction bytes into a typed
+    /// [`DecodedAccessControllerInstruction`]. Useful for building block
+    /// explorers and other tooling that inspects access controller
+    /// instructions outside the context of a submitted transaction.
+    pub fn decode_instruction_data(
+        &self,
+        data: &[u8],
+    ) -> Result<DecodedAccessControllerInstruction, DecodeError> {
+        AccessControllerInstruction::try_from_slice(data)
+            .map_err(|e| DecodeError::Deserialize(e.to_string()))
+    }
+
+    /// Checks the RPC node's health, returning `Err` if it reports itself
+    /// as degraded.
+    pub fn check_rpc_health(&self) -> Result<String, ClientError> {
+        self.rpc_client.get_health()?;
+        Ok("ok".to_string())
+    }
+
+    /// Subscribes to every log line emitted by the access controller
+    /// program and invokes `callback` with each one, blocking until the
+    /// subscription's underlying websocket disconnects.
+    pub fn subscribe_to_program_logs(
+        &self,
+        callback: impl Fn(String) + Send + 'static,
+    ) -> Result<(), ClientError> {
+        let ws_url = websocket_url(&self.rpc_client.url());
+        let (_subscription, receiver) = solana_client::pubsub_client::PubsubClient::logs_subscribe(
+            &ws_url,
+            solana_client::rpc_config::RpcTransactionLogsFilter::Mentions(vec![
+                self.program_id.to_string(),
+            ]),
+            solana_client::rpc_config::RpcTransactionLogsConfig { commitment: None },
+        )
+        .map_err(|e| ClientError::InvalidState(format!("log subscription failed: {}", e)))?;
+
+        for response in receiver.iter() {
+            for line in response.value.logs {
+                callback(line);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the lamport balance funding the access controller state
+    /// account.
+    pub fn get_account_rent(&self) -> Result<u64, ClientError> {
+        Ok(self.rpc_client.get_balance(&self.access_controller_account)?)
+    }
+
+    /// Simulates each instruction set in `instruction_sets` independently
+    /// and returns one result per set, in order. Useful for pre-validating
+    /// a series of planned access list changes in a governance workflow
+    /// before approval.
+    pub fn batch_simulate_instructions(
+        &self,
+        instruction_sets: Vec<Vec<Instruction>>,
+    ) -> Vec<Result<Response<RpcSimulateTransactionResult>, ClientError>> {
+        instruction_sets
+            .into_iter()
+            .map(|instructions| {
+                let transaction = self.compose_transaction(&instructions)?;
+                Ok(self.rpc_client.simulate_transaction(&transaction)?)
+            })
+            .collect()
+    }
+
+    /// Builds, signs, and submits a transaction containing `instructions`,
+    /// waiting for confirmation.
+    pub fn send_transaction(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Signature, ClientError> {
+        let blockhash = self.rpc_client.get_latest_blockhash()?;
+        let mut all_signers: Vec<&Keypair> = vec![&self.payer];
+        all_signers.extend(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        Ok(self.rpc_client.send_and_confirm_transaction(&transaction)?)
+    }
+
+    /// Builds an unsigned transaction from `instructions`, for callers that
+    /// want to add more signers before signing, rather than submitting it
+    /// immediately via [`Self::send_transaction`].
+    pub fn compose_transaction(&self, instructions: &[Instruction]) -> Result<Transaction, ClientError> {
+        let blockhash = self.rpc_client.get_latest_blockhash()?;
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&self.payer.pubkey()));
+        transaction.message.recent_blockhash = blockhash;
+        Ok(transaction)
+    }
+
+    /// Builds the raw `Initialize` instruction, for composing initialization
+    /// with a subsequent instruction in a single atomic transaction.
+    pub fn get_instruction_for_initialize(&self) -> Instruction {
+        AccessControllerInstruction::Initialize.into_instruction(
+            self.program_id,
+            self.access_controller_account,
+            self.payer.pubkey(),
+        )
+    }
+
+    /// Builds the raw `AcceptOwnership` instruction, for transaction
+    /// composition alongside other instructions. Combined with
+    /// [`crate::verifier::VerifierAdminClient::get_instruction_for_accept_ownership`]
+    /// for atomic two-program ownership transfers.
+    pub fn get_instruction_for_accept_ownership(&self) -> Instruction {
+        AccessControllerInstruction::AcceptOwnership.into_instruction(
+            self.program_id,
+            self.access_controller_account,
+            self.payer.pubkey(),
+        )
+    }
+
+    /// Builds an `AddAccess` transaction for `address` without signing it
+    /// and returns it as a base64 string, for governance workflows where a
+    /// proposer builds the transaction and approvers inspect it.
+    pub fn create_add_access_transaction_for_review(
+        &self,
+        address: Pubkey,
+    ) -> Result<String, ClientError> {
+        let instruction = AccessControllerInstruction::AddAccess { address }.into_instruction(
+            self.program_id,
+            self.access_controller_account,
+            self.payer.pubkey(),
+        );
+        let transaction = self.compose_transaction(&[instruction])?;
+        let bytes = bincode::serialize(&transaction)
+            .map_err(|e| ClientError::Deserialize(e.to_string()))?;
+        Ok(BASE64_STANDARD.encode(bytes))
+    }
+
+    /// Restores an access controller from a JSON snapshot of the form
+    /// `["<base58 address>", ...]`, for disaster recovery. Initializes the
+    /// access controller account first if it doesn't already exist, then
+    /// grants access to every address in the snapshot.
+    pub fn rebuild_from_snapshot(&self, addresses_json: &str) -> Result<Vec<Signature>, ClientError> {
+        let mut signatures = Vec::new();
+        if self.get_access_controller_account().is_err() {
+            signatures.push(self.send_transaction(&[self.get_instruction_for_initialize()], &[])?);
+        }
+
+        let snapshot: serde_json::Value = serde_json::from_str(addresses_json)
+            .map_err(|e| ClientError::Deserialize(e.to_string()))?;
+        let entries = snapshot
+            .as_array()
+            .ok_or_else(|| ClientError::Deserialize("snapshot is not a JSON array".into()))?;
+        let addresses: Vec<Pubkey> = entries
+            .iter()
+            .map(|entry| {
+                let address = entry
+                    .as_str()
+                    .ok_or_else(|| ClientError::Deserialize("snapshot entry is not a string".into()))?;
+                address
+                    .parse::<Pubkey>()
+                    .map_err(|e| ClientError::Deserialize(format!("invalid pubkey {}: {}", address, e)))
+            })
+            .collect::<Result<_, _>>()?;
+        signatures.extend(self.batch_add_access(&addresses)?);
+
+        Ok(signatures)
+    }
+
+    /// Adds each of `addresses` to the access list, one transaction per
+    /// address, and returns their signatures in the same order.
+    pub fn batch_add_access(&self, addresses: &[Pubkey]) -> Result<Vec<Signature>, ClientError> {
+        addresses
+            .iter()
+            .map(|address| {
+                let instruction = AccessControllerInstruction::AddAccess { address: *address }
+                    .into_instruction(
+                        self.program_id,
+                        self.access_controller_account,
+                        self.payer.pubkey(),
+                    );
+                self.send_transaction(&[instruction], &[])
+            })
+            .collect()
+    }
+
+    /// Reads a CSV file with one base58-encoded pubkey per row and grants
+    /// each one access via [`Self::batch_add_access`].
+    #[cfg(feature = "csv")]
+    pub fn add_access_from_csv(&self, path: &std::path::Path) -> Result<Vec<Signature>, ClientError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_path(path)?;
+        let mut addresses = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(|e| ClientError::Deserialize(e.to_string()))?;
+            if let Some(field) = record.get(0) {
+                let address = field
+                    .trim()
+                    .parse::<Pubkey>()
+                    .map_err(|e| ClientError::Deserialize(format!("invalid pubkey {}: {}", field, e)))?;
+                addresses.push(address);
+            }
+        }
+        self.batch_add_access(&addresses)
+    }
+
+    /// Fetches and deserializes the access controller program's data
+    /// account.
+    pub fn get_access_controller_account(&self) -> Result<AccessControllerAccount, ClientError> {
+        let data = self
+            .rpc_client
+            .get_account_data(&self.access_controller_account)?;
+        AccessControllerAccount::try_from_slice(&data)
+            .map_err(|e| ClientError::Deserialize(e.to_string()))
+    }
+
+    /// Returns the maximum number of addresses the access list can hold.
+    pub fn get_max_addresses(&self) -> usize {
+        MAX_ACCESS_LIST_ADDRESSES
+    }
+
+    /// Returns the length of the access list without deserializing the
+    /// rest of the account, using a `dataSlice` RPC parameter. This keeps
+    /// data transfer constant regardless of how large the access list has
+    /// grown, unlike [`Self::get_access_controller_account`].
+    ///
+    /// `owner` (32 bytes) and `proposed_owner`'s `Option` tag (1 byte)
+    /// precede the access list's length field in the account's borsh
+    /// layout, and the tag's value determines whether it is followed by
+    /// another 32-byte pubkey. This fetches the tag byte first to compute
+    /// the length field's offset, then fetches just those 4 bytes.
+    pub fn get_access_controller_access_count(&self) -> Result<usize, ClientError> {
+        let tag_slice = self.fetch_data_slice(32, 1)?;
+        let tag = *tag_slice
+            .first()
+            .ok_or_else(|| ClientError::Deserialize("missing proposed_owner tag".into()))?;
+        let proposed_owner_len = if tag == 1 { 32 } else { 0 };
+        let length_offset = 32 + 1 + proposed_owner_len;
+
+        let length_slice = self.fetch_data_slice(length_offset, 4)?;
+        let length_bytes: [u8; 4] = length_slice
+            .try_into()
+            .map_err(|_| ClientError::Deserialize("truncated access list length field".into()))?;
+        Ok(u32::from_le_bytes(length_bytes) as usize)
+    }
+
+    /// Fetches exactly `length` bytes of the access controller account's
+    /// data starting at `offset`, via the `dataSlice` RPC parameter.
+    fn fetch_data_slice(&self, offset: usize, length: usize) -> Result<Vec<u8>, ClientError> {
+        let config = RpcAccountInfoConfig {
+            data_slice: Some(UiDataSliceConfig { offset, length }),
+            ..Default::default()
+        };
+        let response = self
+            .rpc_client
+            .get_account_with_config(&self.access_controller_account, config)?;
+        let account = response.value.ok_or_else(|| {
+            ClientError::InvalidState("access controller account does not exist".into())
+        })?;
+        Ok(account.data)
+    }
+
+    /// Walks the program's full transaction history and counts how many
+    /// `AddAccess` instructions have ever been submitted. This is an audit
+    /// count of total grant events, distinct from the current access list's
+    /// size since revoked addresses are never subtracted from it.
+    pub fn count_access_history(&self) -> Result<u64, ClientError> {
+        let mut count = 0;
+        let mut before = None;
+        loop {
+            let page = self.rpc_client.get_signatures_for_address_with_config(
+                &self.program_id,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    ..Default::default()
+                },
+            )?;
+            let Some(last) = page.last() else { break };
+            before = Some(last.signature.parse().map_err(|_| {
+                ClientError::Deserialize("invalid signature in transaction history".into())
+            })?);
+
+            for entry in &page {
+                let signature: Signature = entry.signature.parse().map_err(|_| {
+                    ClientError::Deserialize("invalid signature in transaction history".into())
+                })?;
+                let confirmed = self
+                    .rpc_client
+                    .get_transaction(&signature, UiTransactionEncoding::Base64)?;
+                let Some(transaction) = confirmed.transaction.transaction.decode() else {
+                    continue;
+                };
+                let account_keys = transaction.message.static_account_keys();
+                for instruction in transaction.message.instructions() {
+                    let Some(program_id) = account_keys.get(instruction.program_id_index as usize)
+                    else {
+                        continue;
+                    };
+                    if *program_id != self.program_id {
+                        continue;
+                    }
+                    if matches!(
+                        AccessControllerInstruction::try_from_slice(&instruction.data),
+                        Ok(AccessControllerInstruction::AddAccess { .. })
+                    ) {
+                        count += 1;
+                    }
+                }
+            }
+
+            if page.len() < 1000 {
+                break;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Renders the access list as an ASCII table, for
+    /// `access-controller-cli list-addresses` output.
+    pub fn list_addresses_as_table_string(&self) -> Result<String, ClientError> {
+        let account = self.get_access_controller_account()?;
+        let mut table = String::from("INDEX | ADDRESS\n");
+        for (index, address) in account.access_list.iter().enumerate() {
+            table.push_str(&format!("{} | {}\n", index, address));
+        }
+        Ok(table)
+    }
+
+    /// Runs a consistency check over the full on-chain access controller
+    /// state and returns every issue found. An empty result means the
+    /// account is healthy.
+    pub fn validate_full_state(&self) -> Result<Vec<StateIssue>, ClientError> {
+        let account = self.get_access_controller_account()?;
+        let mut issues = Vec::new();
+
+        if account.owner == Pubkey::default() {
+            issues.push(StateIssue::new(
+                "owner_unset",
+                "access controller owner is the zero address",
+            ));
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        for address in &account.access_list {
+            if !seen.insert(*address) {
+                issues.push(StateIssue::new(
+                    "duplicate_address",
+                    format!("address {} appears more than once in the access list", address),
+                ));
+            }
+        }
+
+        let max_addresses = self.get_max_addresses();
+        if account.access_list.len() > max_addresses {
+            issues.push(StateIssue::new(
+                "access_list_over_capacity",
+                format!(
+                    "access list has {} addresses, exceeding the maximum of {}",
+                    account.access_list.len(),
+                    max_addresses
+                ),
+            ));
+        }
+
+        Ok(issues)
+    }
+}
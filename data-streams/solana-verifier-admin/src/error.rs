@@ -0,0 +1,58 @@
+use solana_client::client_error::ClientError as RpcClientError;
+use thiserror::Error;
+
+/// Errors surfaced by [`crate::verifier::VerifierAdminClient`] and
+/// [`crate::access_controller::AccessControllerClient`].
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("rpc request failed: {0}")]
+    Rpc(Box<RpcClientError>),
+
+    #[error("failed to deserialize account data: {0}")]
+    Deserialize(String),
+
+    #[error("on-chain state is invalid: {0}")]
+    InvalidState(String),
+
+    #[error("no access controller is configured for this verifier")]
+    AccessControllerNotSet,
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl From<RpcClientError> for ClientError {
+    fn from(err: RpcClientError) -> Self {
+        ClientError::Rpc(Box::new(err))
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for ClientError {
+    fn from(err: csv::Error) -> Self {
+        ClientError::Deserialize(err.to_string())
+    }
+}
+
+/// Errors surfaced when decoding raw instruction bytes, independent of any
+/// RPC call.
+#[derive(Debug, Error)]
+pub enum DecodeError {
+    #[error("failed to decode instruction data: {0}")]
+    Deserialize(String),
+}
+
+/// Errors surfaced by security-invariant checks on
+/// [`crate::verifier::VerifierAdminClient`], distinct from [`ClientError`]
+/// so callers can match on the specific invariant that was violated.
+#[derive(Debug, Error)]
+pub enum VerifierClientError {
+    #[error("insufficient signers: {min_required} required, but the sparsest active config has {actual}")]
+    InsufficientSigners { min_required: usize, actual: usize },
+
+    #[error("maximum of {max} DON configs already reached")]
+    MaxConfigsReached { max: usize },
+
+    #[error(transparent)]
+    Client(#[from] ClientError),
+}
@@ -0,0 +1,159 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+
+/// The decoded form of a verifier instruction's raw bytes, as returned by
+/// [`crate::verifier::VerifierAdminClient::decode_instruction_data`].
+pub type DecodedInstruction = VerifierInstruction;
+
+/// Instructions understood by the Chainlink Data Streams Verifier program.
+///
+/// Each variant's Borsh-serialized bytes become a transaction instruction's
+/// data, grown one variant at a time as client methods need to compose it.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum VerifierInstruction {
+    Initialize,
+    SetConfig {
+        config_id: [u8; 32],
+        signers: Vec<[u8; 20]>,
+        f: u8,
+    },
+    TransferOwnership {
+        new_owner: Pubkey,
+    },
+    AcceptOwnership,
+    Verify {
+        signed_report: Vec<u8>,
+    },
+    RemoveLatestConfig,
+    SetConfigWithActivationTime {
+        config_id: [u8; 32],
+        signers: Vec<[u8; 20]>,
+        f: u8,
+        activation_time: i64,
+    },
+    Realloc {
+        len: u32,
+    },
+    InitializeAccountData,
+    SetAccessController {
+        new_access_controller: Option<Pubkey>,
+    },
+}
+
+impl VerifierInstruction {
+    /// Returns this variant's human-readable name, e.g. `"SetConfig"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            VerifierInstruction::Initialize => "Initialize",
+            VerifierInstruction::SetConfig { .. } => "SetConfig",
+            VerifierInstruction::TransferOwnership { .. } => "TransferOwnership",
+            VerifierInstruction::AcceptOwnership => "AcceptOwnership",
+            VerifierInstruction::Verify { .. } => "Verify",
+            VerifierInstruction::RemoveLatestConfig => "RemoveLatestConfig",
+            VerifierInstruction::SetConfigWithActivationTime { .. } => "SetConfigWithActivationTime",
+            VerifierInstruction::Realloc { .. } => "Realloc",
+            VerifierInstruction::InitializeAccountData => "InitializeAccountData",
+            VerifierInstruction::SetAccessController { .. } => "SetAccessController",
+        }
+    }
+
+    /// Builds the transaction instruction for this variant against
+    /// `program_id`, operating on the verifier account `verifier_data_account`
+    /// with `owner` as the authority signer.
+    pub fn into_instruction(
+        self,
+        program_id: Pubkey,
+        verifier_data_account: Pubkey,
+        owner: Pubkey,
+    ) -> Instruction {
+        Instruction::new_with_borsh(
+            program_id,
+            &self,
+            vec![
+                AccountMeta::new(verifier_data_account, false),
+                AccountMeta::new_readonly(owner, true),
+            ],
+        )
+    }
+}
+
+/// Builds a `Verify` instruction against every report config PDA up front,
+/// so the caller doesn't need to fetch the verifier and access controller
+/// accounts to discover them. See
+/// [`crate::verifier::VerifierAdminClient::verify_cached`].
+pub fn into_verify_instruction(
+    program_id: Pubkey,
+    verifier_data_account: Pubkey,
+    access_controller: Pubkey,
+    config_pdas: &[Pubkey],
+    payer: Pubkey,
+    signed_report: Vec<u8>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(verifier_data_account, false),
+        AccountMeta::new_readonly(access_controller, false),
+    ];
+    accounts.extend(
+        config_pdas
+            .iter()
+            .map(|pda| AccountMeta::new_readonly(*pda, false)),
+    );
+    accounts.push(AccountMeta::new_readonly(payer, true));
+    Instruction::new_with_borsh(
+        program_id,
+        &VerifierInstruction::Verify { signed_report },
+        accounts,
+    )
+}
+
+/// Instructions understood by the Chainlink Data Streams Access Controller
+/// program.
+///
+/// Each variant's Borsh-serialized bytes become a transaction instruction's
+/// data, grown one variant at a time as client methods need to compose it.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum AccessControllerInstruction {
+    AddAccess { address: Pubkey },
+    RemoveAccess { address: Pubkey },
+    Initialize,
+    TransferOwnership { new_owner: Pubkey },
+    AcceptOwnership,
+}
+
+/// The decoded form of an access controller instruction's raw bytes, as
+/// returned by
+/// [`crate::access_controller::AccessControllerClient::decode_instruction_data`].
+pub type DecodedAccessControllerInstruction = AccessControllerInstruction;
+
+impl AccessControllerInstruction {
+    /// Returns this variant's human-readable name, e.g. `"AddAccess"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AccessControllerInstruction::AddAccess { .. } => "AddAccess",
+            AccessControllerInstruction::RemoveAccess { .. } => "RemoveAccess",
+            AccessControllerInstruction::Initialize => "Initialize",
+            AccessControllerInstruction::TransferOwnership { .. } => "TransferOwnership",
+            AccessControllerInstruction::AcceptOwnership => "AcceptOwnership",
+        }
+    }
+
+    /// Builds the transaction instruction for this variant against
+    /// `program_id`, operating on the access controller account
+    /// `access_controller_account` with `owner` as the authority signer.
+    pub fn into_instruction(
+        self,
+        program_id: Pubkey,
+        access_controller_account: Pubkey,
+        owner: Pubkey,
+    ) -> Instruction {
+        Instruction::new_with_borsh(
+            program_id,
+            &self,
+            vec![
+                AccountMeta::new(access_controller_account, false),
+                AccountMeta::new_readonly(owner, true),
+            ],
+        )
+    }
+}
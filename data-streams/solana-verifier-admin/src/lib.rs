@@ -0,0 +1,26 @@
+//! Admin client library for the Chainlink Data Streams Verifier and Access
+//! Controller programs on Solana.
+//!
+//! [`verifier::VerifierAdminClient`] manages DON configs on the verifier
+//! program's data account, and [`access_controller::AccessControllerClient`]
+//! manages the access list gating who may submit reports to it.
+
+pub mod access_controller;
+pub mod error;
+pub mod instructions;
+#[cfg(feature = "squads")]
+pub mod squads;
+pub mod state;
+pub mod verifier;
+
+pub use access_controller::AccessControllerClient;
+pub use error::{ClientError, DecodeError, VerifierClientError};
+pub use instructions::{DecodedAccessControllerInstruction, DecodedInstruction};
+#[cfg(feature = "squads")]
+pub use squads::ProposalSummary;
+pub use state::{
+    AccessControllerAccount, BlockhashExpiryInfo, ConfigChangeRecord, ConfigInstruction,
+    DonConfig, OwnershipInstruction, OwnershipRecord, SetupReport, StateIssue, VerifierAccount,
+    WarmUpCache,
+};
+pub use verifier::VerifierAdminClient;
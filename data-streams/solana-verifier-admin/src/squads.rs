@@ -0,0 +1,87 @@
+//! Minimal client support for inspecting Squads v4 multisig proposals that
+//! target the verifier program. Gated behind the `squads` feature flag.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+
+/// Program ID of the Squads v4 multisig program.
+pub const SQUADS_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("SMPLecH534NA9acpos4G6x7uf3LWbCAwZQE9e8ZekMu");
+
+/// Proposal status indicating it is still awaiting approval or execution.
+pub(crate) const PROPOSAL_STATUS_OPEN: u8 = 0;
+
+/// On-chain layout of a Squads proposal account, as much as this client
+/// needs to summarize it.
+#[derive(Debug, Clone, BorshDeserialize)]
+pub(crate) struct ProposalAccount {
+    pub multisig: Pubkey,
+    pub created_at_slot: u64,
+    pub status: u8,
+    pub approved: Vec<Pubkey>,
+    pub instruction_data: Vec<u8>,
+}
+
+/// A pending proposal on a Squads multisig, found by
+/// [`crate::verifier::VerifierAdminClient::list_open_proposals`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProposalSummary {
+    pub proposal_address: Pubkey,
+    pub instruction_name: String,
+    pub created_at_slot: u64,
+    pub approvals_count: u32,
+}
+
+/// Instructions understood by the Squads v4 multisig program that this
+/// client needs to compose, grown one variant at a time.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub(crate) enum SquadsInstruction {
+    Approve,
+    Execute,
+    CreateSetConfigProposal {
+        config_id: [u8; 32],
+        signers: Vec<[u8; 20]>,
+        f: u8,
+    },
+}
+
+impl SquadsInstruction {
+    /// Builds the transaction instruction for this variant against
+    /// `proposal_address`, with `member` as the multisig member signer.
+    pub fn into_instruction(self, proposal_address: Pubkey, member: Pubkey) -> Instruction {
+        Instruction::new_with_borsh(
+            SQUADS_PROGRAM_ID,
+            &self,
+            vec![
+                AccountMeta::new(proposal_address, false),
+                AccountMeta::new_readonly(member, true),
+            ],
+        )
+    }
+}
+
+/// Builds the instruction that creates a new Squads proposal wrapping a
+/// `SetConfig` call against the verifier program, allocating
+/// `proposal_account` as part of the same instruction.
+pub(crate) fn create_set_config_proposal_instruction(
+    multisig: Pubkey,
+    proposal_account: Pubkey,
+    payer: Pubkey,
+    config_id: [u8; 32],
+    signers: Vec<[u8; 20]>,
+    f: u8,
+) -> Instruction {
+    Instruction::new_with_borsh(
+        SQUADS_PROGRAM_ID,
+        &SquadsInstruction::CreateSetConfigProposal {
+            config_id,
+            signers,
+            f,
+        },
+        vec![
+            AccountMeta::new_readonly(multisig, false),
+            AccountMeta::new(proposal_account, true),
+            AccountMeta::new(payer, true),
+        ],
+    )
+}
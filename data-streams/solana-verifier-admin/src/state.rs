@@ -0,0 +1,145 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+
+/// Approximate wall-clock duration of a single Solana slot.
+pub const APPROX_SLOT_DURATION_MS: u64 = 400;
+
+/// Approximate number of slots a transaction takes to reach `finalized`
+/// commitment, used to estimate average confirmation time from the
+/// cluster's recent per-slot throughput.
+pub const APPROX_CONFIRMATION_SLOT_DEPTH: u64 = 32;
+
+/// The Solana runtime's per-second compute unit budget, used as an upper
+/// bound on sustainable transaction throughput.
+pub const MAX_COMPUTE_UNITS_PER_SECOND: u64 = 48_000_000;
+
+/// Report schema versions this client recognizes. Verify calls are not
+/// checked against this list before they are submitted.
+pub const SUPPORTED_REPORT_SCHEMA_VERSIONS: &[u8] = &[1, 2, 3];
+
+/// Maximum number of bytes an account can grow by in a single `realloc`
+/// instruction, per Solana's runtime-enforced data increase limit.
+pub const MAX_REALLOC_BYTES_PER_STEP: usize = 10_240;
+
+/// Maximum number of DON configs a `VerifierAccount` can hold at once.
+pub const MAX_DON_CONFIGS: usize = 16;
+
+/// Maximum number of signers allowed in a single DON config.
+pub const MAX_SIGNERS_PER_CONFIG: usize = 31;
+
+/// Maximum number of addresses an access controller's access list can hold.
+pub const MAX_ACCESS_LIST_ADDRESSES: usize = 64;
+
+/// A single DON configuration registered with the verifier program.
+///
+/// Signers are the 20-byte Ethereum-style addresses derived from the
+/// secp256k1 keys DON nodes sign reports with, independent of the chain the
+/// verifier program itself runs on.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct DonConfig {
+    pub config_id: [u8; 32],
+    pub signers: Vec<[u8; 20]>,
+    pub f: u8,
+    pub is_active: bool,
+    pub activation_time: i64,
+}
+
+/// Deserialized contents of the verifier program's data account.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct VerifierAccount {
+    pub owner: Pubkey,
+    pub proposed_owner: Option<Pubkey>,
+    pub access_controller: Pubkey,
+    pub configs: Vec<DonConfig>,
+}
+
+/// Deserialized contents of the access controller program's data account.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AccessControllerAccount {
+    pub owner: Pubkey,
+    pub proposed_owner: Option<Pubkey>,
+    pub access_list: Vec<Pubkey>,
+}
+
+/// A single finding produced by a `validate_full_state` consistency check.
+///
+/// An empty `Vec<StateIssue>` means the account is healthy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateIssue {
+    pub code: &'static str,
+    pub description: String,
+}
+
+impl StateIssue {
+    pub fn new(code: &'static str, description: impl Into<String>) -> Self {
+        Self {
+            code,
+            description: description.into(),
+        }
+    }
+}
+
+/// The kind of config-related instruction behind a [`ConfigChangeRecord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigInstruction {
+    SetConfig,
+}
+
+/// A single config-related instruction found in the verifier program's
+/// transaction history, as returned by
+/// [`crate::verifier::VerifierAdminClient::get_config_history_since`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChangeRecord {
+    pub slot: u64,
+    pub instruction_type: ConfigInstruction,
+    pub signature: solana_sdk::signature::Signature,
+}
+
+/// The kind of ownership instruction behind an [`OwnershipRecord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OwnershipInstruction {
+    TransferOwnership,
+    AcceptOwnership,
+}
+
+/// A single ownership transfer event found in the verifier program's
+/// transaction history, as returned by
+/// [`crate::verifier::VerifierAdminClient::get_ownership_history`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnershipRecord {
+    pub slot: u64,
+    pub instruction_type: OwnershipInstruction,
+    pub new_owner: Pubkey,
+    pub signature: solana_sdk::signature::Signature,
+}
+
+/// Accounts fetched ahead of a burst of `verify` calls by
+/// [`crate::verifier::VerifierAdminClient::warm_up`], so
+/// [`crate::verifier::VerifierAdminClient::verify_cached`] can skip account
+/// lookups during the burst.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarmUpCache {
+    pub verifier_account: VerifierAccount,
+    pub access_controller_account: AccessControllerAccount,
+    pub config_pdas: Vec<Pubkey>,
+}
+
+/// A summary of the work performed by
+/// [`crate::verifier::VerifierAdminClient::rebuild_from_snapshot`], so
+/// callers can confirm what a disaster-recovery run actually did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetupReport {
+    pub initialized: bool,
+    pub configs_restored: usize,
+    pub signatures: Vec<solana_sdk::signature::Signature>,
+}
+
+/// How much longer a cached blockhash remains valid for transaction
+/// submission.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlockhashExpiryInfo {
+    pub blockhash: Hash,
+    pub slots_until_expiry: u64,
+    pub seconds_until_expiry: f64,
+}
@@ -0,0 +1,1596 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use borsh::BorshDeserialize;
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_client::rpc_config::RpcSendTransactionConfig;
+use solana_client::rpc_response::{Response, RpcContactInfo, RpcSimulateTransactionResult};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+use solana_transaction_status_client_types::UiTransactionEncoding;
+
+use crate::error::{ClientError, DecodeError, VerifierClientError};
+use crate::instructions::{DecodedInstruction, VerifierInstruction};
+#[cfg(feature = "squads")]
+use crate::squads::ProposalSummary;
+use crate::state::{
+    AccessControllerAccount, BlockhashExpiryInfo, ConfigChangeRecord, ConfigInstruction, DonConfig,
+    OwnershipInstruction, OwnershipRecord, SetupReport, StateIssue, VerifierAccount, WarmUpCache,
+    APPROX_CONFIRMATION_SLOT_DEPTH, APPROX_SLOT_DURATION_MS, MAX_COMPUTE_UNITS_PER_SECOND,
+    MAX_DON_CONFIGS, MAX_REALLOC_BYTES_PER_STEP, MAX_SIGNERS_PER_CONFIG,
+    SUPPORTED_REPORT_SCHEMA_VERSIONS,
+};
+
+/// Returns whether a config's age has reached `expiry_threshold_seconds`,
+/// the pure comparison behind [`VerifierAdminClient::get_next_expiring_config`].
+fn config_age_has_reached_threshold(age_seconds: u64, expiry_threshold_seconds: u64) -> bool {
+    age_seconds >= expiry_threshold_seconds
+}
+
+/// Derives a websocket URL from an RPC client's HTTP(S) URL, by swapping the
+/// scheme, the way Solana's JSON-RPC and PubSub endpoints are conventionally
+/// paired.
+fn websocket_url(rpc_url: &str) -> String {
+    if let Some(rest) = rpc_url.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else {
+        rpc_url.to_string()
+    }
+}
+
+/// A blockhash cached alongside the last block height it is valid through.
+struct CachedBlockhash {
+    blockhash: Hash,
+    last_valid_block_height: u64,
+}
+
+/// A pool of durable nonce accounts drawn from round-robin, so bursts of
+/// transactions aren't bottlenecked by blockhash expiry.
+struct NoncePool {
+    accounts: Vec<(Pubkey, Keypair)>,
+    next: usize,
+}
+
+impl NoncePool {
+    fn next_account(&mut self) -> (Pubkey, Keypair) {
+        let (pubkey, authority) = &self.accounts[self.next];
+        let account = (*pubkey, authority.insecure_clone());
+        self.next = (self.next + 1) % self.accounts.len();
+        account
+    }
+}
+
+/// Admin client for the Chainlink Data Streams Verifier program on Solana.
+///
+/// Wraps an [`RpcClient`] and the verifier program's data account so callers
+/// can inspect and manage DON configs without hand-rolling instruction
+/// encoding for every operation.
+pub struct VerifierAdminClient {
+    pub(crate) rpc_client: RpcClient,
+    pub(crate) program_id: Pubkey,
+    pub(crate) verifier_data_account: Pubkey,
+    pub(crate) payer: Keypair,
+    cached_blockhash: Mutex<Option<CachedBlockhash>>,
+    nonce_pool: Mutex<Option<NoncePool>>,
+}
+
+impl VerifierAdminClient {
+    /// Creates a client targeting `verifier_data_account` on the cluster at
+    /// `rpc_url`, signing transactions with `payer`.
+    pub fn new(
+        rpc_url: &str,
+        program_id: Pubkey,
+        verifier_data_account: Pubkey,
+        payer: Keypair,
+    ) -> Self {
+        Self {
+            rpc_client: RpcClient::new_with_commitment(
+                rpc_url.to_string(),
+                CommitmentConfig::confirmed(),
+            ),
+            program_id,
+            verifier_data_account,
+            payer,
+            cached_blockhash: Mutex::new(None),
+            nonce_pool: Mutex::new(None),
+        }
+    }
+
+    /// Consumes the client and equips it with a pool of durable nonce
+    /// accounts. Once set, [`Self::send_transaction`] pulls a nonce from
+    /// the pool round-robin instead of relying on a recent blockhash,
+    /// eliminating blockhash expiry as a bottleneck for burst workloads.
+    ///
+    /// Each tuple is `(nonce_account, nonce_authority)`.
+    pub fn with_nonce_pool(mut self, nonce_accounts: Vec<(Pubkey, Keypair)>) -> Self {
+        self.nonce_pool = Mutex::new(Some(NoncePool {
+            accounts: nonce_accounts,
+            next: 0,
+        }));
+        self
+    }
+
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    pub fn verifier_data_account(&self) -> Pubkey {
+        self.verifier_data_account
+    }
+
+    /// Alias for [`Self::verifier_data_account`], for external tooling that
+    /// expects a `data_account_address` identity getter alongside
+    /// [`Self::program_id`].
+    pub fn data_account_address(&self) -> Pubkey {
+        self.verifier_data_account
+    }
+
+    pub fn payer(&self) -> &Keypair {
+        &self.payer
+    }
+
+    /// Signs an arbitrary message with the payer keypair. Useful for
+    /// off-chain attestation of admin intent alongside on-chain
+    /// transactions.
+    pub fn sign_message(&self, message: &[u8]) -> Signature {
+        self.payer.sign_message(message)
+    }
+
+    /// Checks that `signature` is a valid signature of `message` by
+    /// `expected_signer`. Useful for validating that a message was signed
+    /// by the expected admin before applying it off-chain.
+    pub fn verify_signature(
+        &self,
+        message: &[u8],
+        signature: &Signature,
+        expected_signer: &Pubkey,
+    ) -> bool {
+        signature.verify(expected_signer.as_ref(), message)
+    }
+
+    /// Decodes raw instruction bytes into a typed [`DecodedInstruction`].
+    /// Useful for building block explorers and other tooling that inspects
+    /// verifier instructions outside the context of a submitted
+    /// transaction.
+    pub fn decode_instruction_data(&self, data: &[u8]) -> Result<DecodedInstruction, DecodeError> {
+        VerifierInstruction::try_from_slice(data)
+            .map_err(|e| DecodeError::Deserialize(e.to_string()))
+    }
+
+    /// Fetches the transaction identified by `sig` and decodes every
+    /// instruction targeting the verifier program into its human-readable
+    /// name, e.g. `"SetConfig"`. Aids manual transaction inspection.
+    pub fn get_transaction_instruction_names(
+        &self,
+        sig: &Signature,
+    ) -> Result<Vec<String>, ClientError> {
+        let confirmed = self
+            .rpc_client
+            .get_transaction(sig, UiTransactionEncoding::Base64)?;
+        let Some(transaction) = confirmed.transaction.transaction.decode() else {
+            return Ok(Vec::new());
+        };
+        let account_keys = transaction.message.static_account_keys();
+        let mut names = Vec::new();
+        for instruction in transaction.message.instructions() {
+            let Some(program_id) = account_keys.get(instruction.program_id_index as usize) else {
+                continue;
+            };
+            if *program_id != self.program_id {
+                continue;
+            }
+            let Ok(decoded) = VerifierInstruction::try_from_slice(&instruction.data) else {
+                continue;
+            };
+            names.push(decoded.name().to_string());
+        }
+        Ok(names)
+    }
+
+    /// Returns the lamport balance funding the verifier data account,
+    /// distinct from [`Self::payer`]'s balance. Tells operators whether the
+    /// account is adequately funded for rent exemption.
+    pub fn get_account_rent(&self) -> Result<u64, ClientError> {
+        Ok(self.rpc_client.get_balance(&self.verifier_data_account)?)
+    }
+
+    /// Checks that every active DON config has at least `min_signers`
+    /// signers, a security invariant for sustaining the `3f + 1`
+    /// fault-tolerance bound against signer churn.
+    pub fn assert_min_signer_count(&self, min_signers: usize) -> Result<(), VerifierClientError> {
+        let account = self.get_verifier_account()?;
+        let sparsest = account
+            .configs
+            .iter()
+            .filter(|config| config.is_active)
+            .map(|config| config.signers.len())
+            .min();
+        if let Some(actual) = sparsest {
+            if actual < min_signers {
+                return Err(VerifierClientError::InsufficientSigners {
+                    min_required: min_signers,
+                    actual,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns how many DON configs are currently registered on the
+    /// verifier account.
+    pub fn get_config_count(&self) -> Result<usize, ClientError> {
+        Ok(self.get_verifier_account()?.configs.len())
+    }
+
+    /// Returns `Err(VerifierClientError::MaxConfigsReached)` if the verifier
+    /// account already holds [`MAX_DON_CONFIGS`] configs, so callers can
+    /// check capacity before a [`Self::set_config`] call that would
+    /// otherwise fail on-chain.
+    pub fn assert_max_don_configs_not_reached(&self) -> Result<(), VerifierClientError> {
+        if self.get_config_count()? >= MAX_DON_CONFIGS {
+            return Err(VerifierClientError::MaxConfigsReached { max: MAX_DON_CONFIGS });
+        }
+        Ok(())
+    }
+
+    /// Compacts the verifier account's config indices after removals have
+    /// left gaps.
+    ///
+    /// The on-chain program does not support renumbering configs in place —
+    /// it only exposes [`Self::get_instruction_for_remove_latest_config`]
+    /// (pops the last entry) and [`Self::set_config`] (appends a new one).
+    /// This method works within that constraint: it pops every existing
+    /// config, then re-registers the ones that were active, sorted by
+    /// ascending `activation_time`, dropping any inactive configs entirely.
+    /// The result is that active configs occupy a contiguous range of
+    /// indices starting at zero, at the cost of one transaction per config
+    /// removed and re-added.
+    pub fn defrag_config_indices(&self) -> Result<Vec<Signature>, ClientError> {
+        let account = self.get_verifier_account()?;
+        let mut active: Vec<_> = account.configs.iter().filter(|c| c.is_active).cloned().collect();
+        active.sort_by_key(|c| c.activation_time);
+
+        let mut signatures = Vec::with_capacity(account.configs.len() + active.len());
+        for _ in 0..account.configs.len() {
+            let instruction = self.get_instruction_for_remove_latest_config();
+            signatures.push(self.send_transaction(&[instruction], &[])?);
+        }
+        for config in active {
+            signatures.push(self.set_config(config.config_id, config.signers, config.f)?);
+        }
+        Ok(signatures)
+    }
+
+    /// Returns the current Unix timestamp from the local system clock, for
+    /// evaluating whether a config's `activation_time` has elapsed.
+    pub fn get_current_unix_timestamp(&self) -> i64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs() as i64)
+            .unwrap_or(0)
+    }
+
+    /// Returns the DON config that would be effective at `timestamp`: the
+    /// active config with the greatest `activation_time` not after it, or
+    /// `None` if no active config has activated yet by that time.
+    pub fn get_config_at_time(&self, timestamp: i64) -> Result<Option<DonConfig>, ClientError> {
+        let account = self.get_verifier_account()?;
+        Ok(account
+            .configs
+            .into_iter()
+            .filter(|config| config.is_active && config.activation_time <= timestamp)
+            .max_by_key(|config| config.activation_time))
+    }
+
+    /// Returns the DON config currently in effect, per the local system
+    /// clock. The most common runtime call pattern for checking what
+    /// config applies to reports submitted right now.
+    pub fn get_config_at_current_time(&self) -> Result<Option<DonConfig>, ClientError> {
+        self.get_config_at_time(self.get_current_unix_timestamp())
+    }
+
+    /// Returns whether a DON config is currently in effect, per
+    /// [`Self::get_config_at_current_time`]. A fast health-check call for
+    /// monitoring systems that don't need the config's contents, only
+    /// whether one exists.
+    pub fn has_active_config(&self) -> Result<bool, ClientError> {
+        Ok(self.get_config_at_current_time()?.is_some())
+    }
+
+    /// Finds the active config closest to expiry, for scheduled rotation
+    /// tooling.
+    ///
+    /// `DonConfig` has no explicit expiry field, so this treats
+    /// `activation_time` as the aging clock: the active config with the
+    /// smallest `activation_time` is the oldest, and therefore the
+    /// soonest candidate for rotation. Returns `Some((index, activation_time))`
+    /// for that config once its age (the current time minus its
+    /// `activation_time`) has reached `expiry_threshold_seconds`, or `None`
+    /// if there is no active config, or the oldest one hasn't reached the
+    /// threshold yet.
+    pub fn get_next_expiring_config(
+        &self,
+        expiry_threshold_seconds: u64,
+    ) -> Result<Option<(u64, i64)>, ClientError> {
+        let account = self.get_verifier_account()?;
+        let now = self.get_current_unix_timestamp();
+        let oldest = account
+            .configs
+            .iter()
+            .enumerate()
+            .filter(|(_, config)| config.is_active)
+            .min_by_key(|(_, config)| config.activation_time);
+
+        let Some((index, config)) = oldest else {
+            return Ok(None);
+        };
+        let age_seconds = now.saturating_sub(config.activation_time).max(0) as u64;
+        if !config_age_has_reached_threshold(age_seconds, expiry_threshold_seconds) {
+            return Ok(None);
+        }
+        Ok(Some((index as u64, config.activation_time)))
+    }
+
+    /// Renders the verifier account's config list as an ASCII table, for
+    /// `verifier-admin-cli list-configs` output.
+    pub fn list_configs_as_table_string(&self) -> Result<String, ClientError> {
+        let account = self.get_verifier_account()?;
+        let mut table = String::from("INDEX | ACTIVE | F | SIGNER_COUNT | ACTIVATION_TIME | DIGEST\n");
+        for (index, config) in account.configs.iter().enumerate() {
+            table.push_str(&format!(
+                "{} | {} | {} | {} | {} | {}\n",
+                index,
+                config.is_active,
+                config.f,
+                config.signers.len(),
+                config.activation_time,
+                hex::encode(config.config_id),
+            ));
+        }
+        Ok(table)
+    }
+
+    /// Renders the config at `index` as a Markdown document, for
+    /// auto-generating deployment documentation.
+    pub fn get_config_as_markdown(&self, index: u64) -> Result<String, ClientError> {
+        let account = self.get_verifier_account()?;
+        let config = account.configs.get(index as usize).ok_or_else(|| {
+            ClientError::InvalidState(format!("no config at index {}", index))
+        })?;
+        Ok(format!(
+            "# DON Config {index}\n\n\
+             - **Active**: {active}\n\
+             - **f**: {f}\n\
+             - **Activation time**: {activation_time}\n\n\
+             ```\n\
+             signers:\n{signers}\
+             ```\n",
+            index = index,
+            active = config.is_active,
+            f = config.f,
+            activation_time = config.activation_time,
+            signers = config
+                .signers
+                .iter()
+                .map(|signer| format!("  0x{}\n", hex::encode(signer)))
+                .collect::<String>(),
+        ))
+    }
+
+    /// Checks the RPC node's health, returning `Err` if it reports itself
+    /// as degraded. Worth calling at the start of long-running operations
+    /// like [`Self::realloc_full_size_with_retry`] or batched verify submissions, so a bad RPC
+    /// node is caught before burning through many requests against it.
+    pub fn check_rpc_health(&self) -> Result<String, ClientError> {
+        self.rpc_client.get_health()?;
+        Ok("ok".to_string())
+    }
+
+    /// Subscribes to every log line emitted by the verifier program and
+    /// invokes `callback` with each one, blocking until the subscription's
+    /// underlying websocket disconnects. Lower-level than subscribing to
+    /// account changes, since it surfaces raw program log output (e.g.
+    /// `msg!` lines) rather than parsed account state.
+    pub fn subscribe_to_program_logs(
+        &self,
+        callback: impl Fn(String) + Send + 'static,
+    ) -> Result<(), ClientError> {
+        let ws_url = websocket_url(&self.rpc_client.url());
+        let (_subscription, receiver) = solana_client::pubsub_client::PubsubClient::logs_subscribe(
+            &ws_url,
+            solana_client::rpc_config::RpcTransactionLogsFilter::Mentions(vec![
+                self.program_id.to_string(),
+            ]),
+            solana_client::rpc_config::RpcTransactionLogsConfig { commitment: None },
+        )
+        .map_err(|e| ClientError::InvalidState(format!("log subscription failed: {}", e)))?;
+
+        for response in receiver.iter() {
+            for line in response.value.logs {
+                callback(line);
+            }
+        }
+        Ok(())
+    }
+
+    /// Simulates each instruction set in `instruction_sets` independently
+    /// and returns one result per set, in order. Each set is paired with the
+    /// signers it needs beyond `self.payer` (e.g. a fresh proposal keypair
+    /// for a Squads `CreateSetConfigProposal`), the same way
+    /// [`Self::send_transaction`] takes signers. Useful for pre-validating a
+    /// series of planned transactions in a governance workflow before
+    /// approval.
+    pub fn batch_simulate_instructions(
+        &self,
+        instruction_sets: Vec<(Vec<Instruction>, Vec<&Keypair>)>,
+    ) -> Vec<Result<Response<RpcSimulateTransactionResult>, ClientError>> {
+        instruction_sets
+            .into_iter()
+            .map(|(instructions, signers)| {
+                let transaction = self.build_transaction(&instructions, &signers)?;
+                Ok(self.rpc_client.simulate_transaction(&transaction)?)
+            })
+            .collect()
+    }
+
+    /// Returns every account owned by the verifier program whose data length
+    /// falls within `[min_bytes, max_bytes]`. The Solana `dataSize` filter
+    /// only matches an exact size, so this fetches every program account and
+    /// filters client-side; useful for spotting under- or over-allocated
+    /// accounts after a partial `realloc` sequence.
+    pub fn get_program_accounts_by_size(
+        &self,
+        min_bytes: usize,
+        max_bytes: usize,
+    ) -> Result<Vec<(Pubkey, solana_sdk::account::Account)>, ClientError> {
+        let accounts = self.rpc_client.get_program_accounts(&self.program_id)?;
+        Ok(accounts
+            .into_iter()
+            .filter(|(_, account)| {
+                let len = account.data.len();
+                len >= min_bytes && len <= max_bytes
+            })
+            .collect())
+    }
+
+    /// Lists the cluster's validator nodes, for debugging why transactions
+    /// aren't landing on specific validators.
+    pub fn get_cluster_nodes(&self) -> Result<Vec<RpcContactInfo>, ClientError> {
+        Ok(self.rpc_client.get_cluster_nodes()?)
+    }
+
+    /// Returns the cluster's current epoch info. Not used by
+    /// [`Self::get_blockhash_expiry_info`], which assumes the static
+    /// [`APPROX_SLOT_DURATION_MS`] slot duration.
+    pub fn get_epoch_info(&self) -> Result<solana_sdk::epoch_info::EpochInfo, ClientError> {
+        Ok(self.rpc_client.get_epoch_info()?)
+    }
+
+    /// Estimates the cluster's current milliseconds-per-slot from its
+    /// recent performance samples, for more accurate timing than the
+    /// static [`APPROX_SLOT_DURATION_MS`] assumption.
+    pub fn get_slot_duration_ms(&self) -> Result<f64, ClientError> {
+        let samples = self.rpc_client.get_recent_performance_samples(Some(1))?;
+        let sample = samples
+            .first()
+            .ok_or_else(|| ClientError::InvalidState("no recent performance samples".into()))?;
+        if sample.num_slots == 0 {
+            return Err(ClientError::InvalidState(
+                "performance sample covers zero slots".into(),
+            ));
+        }
+        Ok(sample.sample_period_secs as f64 * 1000.0 / sample.num_slots as f64)
+    }
+
+    /// Queries recent prioritization fees paid against the verifier data
+    /// account and its access controller, and returns the fee at
+    /// `percentile` (0-100) among them, in microlamports per compute unit.
+    /// The core of an auto priority fee lookup for [`Self::set_config`]
+    /// submissions on a congested cluster.
+    pub fn compute_priority_fee_for_percentile(&self, percentile: u8) -> Result<u64, ClientError> {
+        let verifier_account = self.get_verifier_account()?;
+        let addresses = [self.verifier_data_account, verifier_account.access_controller];
+        let samples = self.rpc_client.get_recent_prioritization_fees(&addresses)?;
+        let mut fees: Vec<u64> = samples.iter().map(|sample| sample.prioritization_fee).collect();
+        if fees.is_empty() {
+            return Ok(0);
+        }
+        fees.sort_unstable();
+        let index = (fees.len() - 1) * percentile.min(100) as usize / 100;
+        Ok(fees[index])
+    }
+
+    /// Fetches and deserializes the verifier program's data account.
+    pub fn get_verifier_account(&self) -> Result<VerifierAccount, ClientError> {
+        let data = self.rpc_client.get_account_data(&self.verifier_data_account)?;
+        VerifierAccount::try_from_slice(&data)
+            .map_err(|e| ClientError::Deserialize(e.to_string()))
+    }
+
+    /// Returns the maximum number of DON configs a `VerifierAccount` can
+    /// hold at once, so operators can check capacity before calling
+    /// [`Self::set_config`].
+    pub fn get_max_don_configs(&self) -> usize {
+        MAX_DON_CONFIGS
+    }
+
+    /// Returns the report schema versions this client recognizes. A
+    /// compile-time constant; no method of this client checks a report's
+    /// schema version against it before submitting it on-chain.
+    pub fn get_supported_report_schema_versions() -> &'static [u8] {
+        SUPPORTED_REPORT_SCHEMA_VERSIONS
+    }
+
+    /// Builds an unsigned transaction from `instructions`, for callers that
+    /// want to add more signers before signing, rather than submitting it
+    /// immediately via [`Self::send_transaction`].
+    pub fn compose_transaction(&self, instructions: &[Instruction]) -> Result<Transaction, ClientError> {
+        let blockhash = self.rpc_client.get_latest_blockhash()?;
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&self.payer.pubkey()));
+        transaction.message.recent_blockhash = blockhash;
+        Ok(transaction)
+    }
+
+    /// Derives the report config PDA for `config_id`.
+    pub fn derive_config_pda(&self, config_id: &[u8; 32]) -> Pubkey {
+        Pubkey::find_program_address(&[b"config", config_id], &self.program_id).0
+    }
+
+    /// Fetches the verifier account, its access controller account, and
+    /// every registered config's report PDA, caching them ahead of a burst of
+    /// `verify` calls. Pass the result to [`Self::verify_cached`] to skip
+    /// account lookups during the burst.
+    pub fn warm_up(&self) -> Result<WarmUpCache, ClientError> {
+        let verifier_account = self.get_verifier_account()?;
+        let access_controller_account =
+            self.fetch_access_controller_account(&verifier_account.access_controller)?;
+        let config_pdas = verifier_account
+            .configs
+            .iter()
+            .map(|config| self.derive_config_pda(&config.config_id))
+            .collect();
+        Ok(WarmUpCache {
+            verifier_account,
+            access_controller_account,
+            config_pdas,
+        })
+    }
+
+    /// Fetches and deserializes the access controller account at `address`.
+    fn fetch_access_controller_account(
+        &self,
+        address: &Pubkey,
+    ) -> Result<AccessControllerAccount, ClientError> {
+        let data = self.rpc_client.get_account_data(address)?;
+        AccessControllerAccount::try_from_slice(&data)
+            .map_err(|e| ClientError::Deserialize(e.to_string()))
+    }
+
+    /// Fetches the access controller account referenced by the verifier
+    /// account, without requiring the caller to manage a separate
+    /// [`crate::access_controller::AccessControllerClient`] instance for
+    /// this common read pattern.
+    pub fn get_access_controller_state(&self) -> Result<AccessControllerAccount, ClientError> {
+        let verifier_account = self.get_verifier_account()?;
+        if verifier_account.access_controller == Pubkey::default() {
+            return Err(ClientError::AccessControllerNotSet);
+        }
+        self.fetch_access_controller_account(&verifier_account.access_controller)
+    }
+
+    /// Returns whether `address` is permitted to submit reports to this
+    /// verifier, per the access list of its configured access controller.
+    pub fn is_address_permitted_to_verify(&self, address: &Pubkey) -> Result<bool, ClientError> {
+        let access_controller = self.get_access_controller_state()?;
+        Ok(access_controller.access_list.contains(address))
+    }
+
+    /// Submits `signed_report` for verification using the account addresses
+    /// in `cache`, skipping the account lookups [`Self::warm_up`] already
+    /// did. Reduces latency for the first call in a burst.
+    pub fn verify_cached(
+        &self,
+        signed_report: Vec<u8>,
+        cache: &WarmUpCache,
+    ) -> Result<Signature, ClientError> {
+        let instruction = crate::instructions::into_verify_instruction(
+            self.program_id,
+            self.verifier_data_account,
+            cache.verifier_account.access_controller,
+            &cache.config_pdas,
+            self.payer.pubkey(),
+            signed_report,
+        );
+        self.send_transaction(&[instruction], &[])
+    }
+
+    /// Parses a list of hex-encoded (optionally `0x`-prefixed) 20-byte
+    /// signer addresses.
+    pub fn signers_from_hex_vec(&self, hex_addresses: &[String]) -> Result<Vec<[u8; 20]>, ClientError> {
+        hex_addresses
+            .iter()
+            .map(|address| {
+                let trimmed = address.trim().trim_start_matches("0x");
+                let bytes = hex::decode(trimmed).map_err(|e| {
+                    ClientError::Deserialize(format!("invalid hex signer address {}: {}", address, e))
+                })?;
+                bytes.try_into().map_err(|_| {
+                    ClientError::Deserialize(format!("signer address {} is not 20 bytes", address))
+                })
+            })
+            .collect()
+    }
+
+    /// Validates that `signers` satisfies the `3f + 1` fault-tolerance
+    /// bound and contains no duplicates.
+    pub fn check_signer_set_validity(&self, signers: &[[u8; 20]], f: u8) -> Result<(), ClientError> {
+        let required = 3 * f as usize + 1;
+        if signers.len() < required {
+            return Err(ClientError::InvalidState(format!(
+                "{} signers is below the fault-tolerance bound of {} for f={}",
+                signers.len(),
+                required,
+                f
+            )));
+        }
+        let unique: std::collections::HashSet<_> = signers.iter().collect();
+        if unique.len() != signers.len() {
+            return Err(ClientError::InvalidState(
+                "signer set contains duplicates".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Registers a new DON config secured by `signers` with byzantine fault
+    /// tolerance `f`.
+    pub fn set_config(
+        &self,
+        config_id: [u8; 32],
+        signers: Vec<[u8; 20]>,
+        f: u8,
+    ) -> Result<Signature, ClientError> {
+        self.check_signer_set_validity(&signers, f)?;
+        let instruction = VerifierInstruction::SetConfig {
+            config_id,
+            signers,
+            f,
+        }
+        .into_instruction(self.program_id, self.verifier_data_account, self.payer.pubkey());
+        self.send_transaction(&[instruction], &[])
+    }
+
+    /// Restores a verifier from a JSON snapshot of the form
+    /// `[{"signers": ["<hex>", ...], "f": <u8>}, ...]`, for disaster
+    /// recovery. Initializes the verifier account first if it doesn't
+    /// already exist, then registers every config in the snapshot.
+    pub fn rebuild_from_snapshot(&self, snapshot_json: &str) -> Result<SetupReport, ClientError> {
+        let initialized = self.get_verifier_account().is_err();
+        let mut signatures = Vec::new();
+        if initialized {
+            let instruction = self.get_instruction_for_initialize();
+            signatures.push(self.send_transaction(&[instruction], &[])?);
+        }
+
+        let snapshot: serde_json::Value = serde_json::from_str(snapshot_json)
+            .map_err(|e| ClientError::Deserialize(e.to_string()))?;
+        let configs = snapshot
+            .as_array()
+            .ok_or_else(|| ClientError::Deserialize("snapshot is not a JSON array".into()))?;
+        for entry in configs {
+            let f = entry
+                .get("f")
+                .and_then(serde_json::Value::as_u64)
+                .ok_or_else(|| ClientError::Deserialize("config entry missing f".into()))?
+                as u8;
+            let signer_strings: Vec<String> = entry
+                .get("signers")
+                .and_then(|v| v.as_array())
+                .ok_or_else(|| ClientError::Deserialize("config entry missing signers".into()))?
+                .iter()
+                .filter_map(|s| s.as_str().map(str::to_string))
+                .collect();
+            let signers = self.signers_from_hex_vec(&signer_strings)?;
+            let config_id = Self::derive_config_id(&signers, f);
+            signatures.push(self.set_config(config_id, signers, f)?);
+        }
+
+        Ok(SetupReport {
+            initialized,
+            configs_restored: configs.len(),
+            signatures,
+        })
+    }
+
+    /// Registers a new DON config secured by `signers`, prepending a
+    /// `SetComputeUnitPrice` instruction priced at `microlamports_per_cu`.
+    /// Saves operators the boilerplate of manually adding the compute
+    /// budget instruction ahead of a congestion-sensitive `set_config`
+    /// call.
+    pub fn set_config_with_priority_fee(
+        &self,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+        microlamports_per_cu: u64,
+    ) -> Result<Signature, ClientError> {
+        self.check_signer_set_validity(&signers, f)?;
+        let config_id = Self::derive_config_id(&signers, f);
+        let priority_fee_instruction =
+            solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_price(
+                microlamports_per_cu,
+            );
+        let set_config_instruction = VerifierInstruction::SetConfig {
+            config_id,
+            signers,
+            f,
+        }
+        .into_instruction(self.program_id, self.verifier_data_account, self.payer.pubkey());
+        self.send_transaction(&[priority_fee_instruction, set_config_instruction], &[])
+    }
+
+    /// Builds a `SetConfig` transaction without signing it and returns it as
+    /// a base64 string, for proposing config changes in a governance
+    /// workflow.
+    pub fn create_set_config_transaction_for_review(
+        &self,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+    ) -> Result<String, ClientError> {
+        self.check_signer_set_validity(&signers, f)?;
+        let config_id = Self::derive_config_id(&signers, f);
+        let instruction = VerifierInstruction::SetConfig {
+            config_id,
+            signers,
+            f,
+        }
+        .into_instruction(self.program_id, self.verifier_data_account, self.payer.pubkey());
+        let transaction = self.compose_transaction(&[instruction])?;
+        Self::encode_transaction_for_review(&transaction)
+    }
+
+    /// Derives a config id by hashing the signer set and `f`, mirroring how
+    /// OCR config digests are derived from config content.
+    fn derive_config_id(signers: &[[u8; 20]], f: u8) -> [u8; 32] {
+        let mut hasher_input = Vec::new();
+        for signer in signers {
+            hasher_input.extend_from_slice(signer);
+        }
+        hasher_input.push(f);
+        solana_sdk::hash::hash(&hasher_input).to_bytes()
+    }
+
+    /// Builds the raw `Initialize` instruction, for composing initialization
+    /// with a subsequent instruction in a single atomic transaction.
+    pub fn get_instruction_for_initialize(&self) -> Instruction {
+        VerifierInstruction::Initialize.into_instruction(
+            self.program_id,
+            self.verifier_data_account,
+            self.payer.pubkey(),
+        )
+    }
+
+    /// Builds the raw `TransferOwnership` instruction proposing
+    /// `proposed_owner` as the verifier program's new owner, for transaction
+    /// composition alongside other instructions.
+    pub fn get_instruction_for_transfer_ownership(&self, proposed_owner: Pubkey) -> Instruction {
+        VerifierInstruction::TransferOwnership {
+            new_owner: proposed_owner,
+        }
+        .into_instruction(self.program_id, self.verifier_data_account, self.payer.pubkey())
+    }
+
+    /// Builds the raw `AcceptOwnership` instruction, for transaction
+    /// composition alongside other instructions. Combined with
+    /// [`crate::access_controller::AccessControllerClient::get_instruction_for_accept_ownership`]
+    /// for atomic two-program ownership transfers.
+    pub fn get_instruction_for_accept_ownership(&self) -> Instruction {
+        VerifierInstruction::AcceptOwnership.into_instruction(
+            self.program_id,
+            self.verifier_data_account,
+            self.payer.pubkey(),
+        )
+    }
+
+    /// Builds the raw `RemoveLatestConfig` instruction, which retires the
+    /// most recently registered DON config, for composing multi-instruction
+    /// transactions (e.g. alongside a [`Self::set_config`] that replaces
+    /// it).
+    pub fn get_instruction_for_remove_latest_config(&self) -> Instruction {
+        VerifierInstruction::RemoveLatestConfig.into_instruction(
+            self.program_id,
+            self.verifier_data_account,
+            self.payer.pubkey(),
+        )
+    }
+
+    /// Builds the raw `SetConfigWithActivationTime` instruction, for
+    /// composing a config change that only takes effect at a specific
+    /// future `activation_time` (Unix timestamp) without sending it
+    /// immediately.
+    pub fn get_instruction_for_set_config_with_activation_time(
+        &self,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+        activation_time: i64,
+    ) -> Result<Instruction, ClientError> {
+        self.check_signer_set_validity(&signers, f)?;
+        let config_id = Self::derive_config_id(&signers, f);
+        Ok(VerifierInstruction::SetConfigWithActivationTime {
+            config_id,
+            signers,
+            f,
+            activation_time,
+        }
+        .into_instruction(self.program_id, self.verifier_data_account, self.payer.pubkey()))
+    }
+
+    /// Builds the raw `Realloc` instruction growing the verifier data
+    /// account by `len` bytes, for composing or inspecting a realloc step
+    /// without sending it immediately.
+    pub fn get_instruction_for_realloc(&self, len: usize) -> Instruction {
+        VerifierInstruction::Realloc { len: len as u32 }.into_instruction(
+            self.program_id,
+            self.verifier_data_account,
+            self.payer.pubkey(),
+        )
+    }
+
+    /// Builds the raw `InitializeAccountData` instruction, which sets up
+    /// the verifier data account's initial layout once it has been grown to
+    /// full size via [`Self::get_instruction_for_realloc`], for transaction
+    /// composition.
+    pub fn get_instruction_for_init_data(&self) -> Instruction {
+        VerifierInstruction::InitializeAccountData.into_instruction(
+            self.program_id,
+            self.verifier_data_account,
+            self.payer.pubkey(),
+        )
+    }
+
+    /// Builds the raw `SetAccessController` instruction pointing the
+    /// verifier at `new_access_controller` (or detaching it entirely when
+    /// `None`), enabling atomic access-controller updates in composed
+    /// transactions.
+    pub fn get_instruction_for_set_access_controller(
+        &self,
+        new_access_controller: Option<Pubkey>,
+    ) -> Instruction {
+        VerifierInstruction::SetAccessController {
+            new_access_controller,
+        }
+        .into_instruction(self.program_id, self.verifier_data_account, self.payer.pubkey())
+    }
+
+    /// Base64-encodes `transaction`'s bincode-serialized bytes, for
+    /// governance workflows where a proposer builds an unsigned transaction
+    /// and approvers inspect it before signing.
+    fn encode_transaction_for_review(transaction: &Transaction) -> Result<String, ClientError> {
+        let bytes = bincode::serialize(transaction)
+            .map_err(|e| ClientError::Deserialize(e.to_string()))?;
+        Ok(BASE64_STANDARD.encode(bytes))
+    }
+
+    /// Builds the `Initialize` transaction without signing it and returns it
+    /// as a base64 string, for the multisig governance workflow where a
+    /// proposer builds the transaction and approvers inspect it.
+    pub fn create_initialize_transaction_for_review(&self) -> Result<String, ClientError> {
+        let instruction = self.get_instruction_for_initialize();
+        let transaction = self.compose_transaction(&[instruction])?;
+        Self::encode_transaction_for_review(&transaction)
+    }
+
+    /// Reads a CSV file of hex-encoded 20-byte signer addresses (one per
+    /// row) and calls [`Self::set_config`] with a config id derived from
+    /// hashing the signer set, mirroring how OCR config digests are
+    /// derived from config content.
+    #[cfg(feature = "csv")]
+    pub fn set_config_from_csv_file(
+        &self,
+        path: &std::path::Path,
+        f: u8,
+    ) -> Result<Signature, ClientError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_path(path)?;
+        let mut raw_addresses = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(|e| ClientError::Deserialize(e.to_string()))?;
+            if let Some(field) = record.get(0) {
+                raw_addresses.push(field.to_string());
+            }
+        }
+        let signers = self.signers_from_hex_vec(&raw_addresses)?;
+        self.check_signer_set_validity(&signers, f)?;
+        let config_id = Self::derive_config_id(&signers, f);
+        self.set_config(config_id, signers, f)
+    }
+
+    /// Returns the number of bytes a `VerifierAccount` must occupy on-chain,
+    /// assuming the account is sized for the maximum number of DON configs
+    /// and signers per config.
+    pub fn get_account_size_requirement(&self) -> usize {
+        let don_config_size = 32 + (4 + MAX_SIGNERS_PER_CONFIG * 20) + 1 + 1 + 8;
+        32 + 1 + 32 + 4 + crate::state::MAX_DON_CONFIGS * don_config_size
+    }
+
+    /// Returns how many more `realloc` steps are needed to grow the
+    /// verifier data account up to [`Self::get_account_size_requirement`],
+    /// given the runtime's per-instruction data increase limit.
+    pub fn get_realloc_steps_remaining(&self) -> Result<u64, ClientError> {
+        let current_len = self
+            .rpc_client
+            .get_account(&self.verifier_data_account)?
+            .data
+            .len();
+        let target_len = self.get_account_size_requirement();
+        let remaining = target_len.saturating_sub(current_len);
+        Ok(remaining.div_ceil(MAX_REALLOC_BYTES_PER_STEP) as u64)
+    }
+
+    /// Drives the verifier data account through every remaining `realloc`
+    /// step up to [`Self::get_account_size_requirement`], retrying each
+    /// step up to `max_retries_per_step` times on transient RPC errors.
+    /// Important for long realloc sequences over flaky connections.
+    /// Returns the signature of the final step submitted.
+    pub fn realloc_full_size_with_retry(
+        &self,
+        max_retries_per_step: u32,
+    ) -> Result<Signature, ClientError> {
+        let mut signature = None;
+        loop {
+            let steps_remaining = self.get_realloc_steps_remaining()?;
+            if steps_remaining == 0 {
+                break;
+            }
+            let instruction = self.get_instruction_for_realloc(MAX_REALLOC_BYTES_PER_STEP);
+            let mut attempt = 0;
+            loop {
+                match self.send_transaction(std::slice::from_ref(&instruction), &[]) {
+                    Ok(step_signature) => {
+                        signature = Some(step_signature);
+                        break;
+                    }
+                    Err(_) if attempt < max_retries_per_step => {
+                        attempt += 1;
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+        signature.ok_or_else(|| {
+            ClientError::InvalidState("verifier account already at full size".into())
+        })
+    }
+
+    /// Estimates the fee for a single-signature transaction at the current
+    /// cluster fee rate.
+    fn estimate_fee(&self) -> Result<u64, ClientError> {
+        let blockhash = self.blockhash()?;
+        let mut message = solana_sdk::message::Message::new(&[], Some(&self.payer.pubkey()));
+        message.recent_blockhash = blockhash;
+        Ok(self.rpc_client.get_fee_for_message(&message)?)
+    }
+
+    /// Estimates the total lamport cost of driving the verifier data
+    /// account through every remaining `realloc` step, so operators have a
+    /// single number before starting [`Self::realloc_full_size_with_retry`].
+    pub fn estimate_realloc_cost(&self) -> Result<u64, ClientError> {
+        let steps = self.get_realloc_steps_remaining()?;
+        let fee_per_step = self.estimate_fee()?;
+        Ok(steps * fee_per_step)
+    }
+
+    /// Estimates how long a full `initialize` + realloc + `init_data` setup
+    /// sequence will take to confirm, to set user expectations in CLIs.
+    pub fn estimate_full_setup_duration(&self) -> Result<Duration, ClientError> {
+        let realloc_steps = self.get_realloc_steps_remaining()?;
+        // Each realloc step needs roughly two slots to confirm, plus one
+        // slot each for the initial `initialize` and `init_data` calls.
+        let slots = realloc_steps * 2 + 2;
+        Ok(Duration::from_millis(slots * APPROX_SLOT_DURATION_MS))
+    }
+
+    /// Simulates a `Verify` instruction and returns the compute units it
+    /// consumed. Used by [`Self::get_verify_throughput_capacity`] to derive
+    /// a compute-budget-based upper bound on sustainable verify rate.
+    pub fn get_compute_units_used_by_verify(&self) -> Result<u64, ClientError> {
+        let instruction = VerifierInstruction::Verify {
+            signed_report: Vec::new(),
+        }
+        .into_instruction(self.program_id, self.verifier_data_account, self.payer.pubkey());
+        let transaction = self.build_transaction(&[instruction], &[])?;
+        let result = self.rpc_client.simulate_transaction(&transaction)?;
+        Ok(result.value.units_consumed.unwrap_or(0))
+    }
+
+    /// Estimates the maximum sustainable `verify` call rate, as the
+    /// minimum of two bounds: the inverse of the average confirmation
+    /// time, and the network's compute budget of
+    /// [`MAX_COMPUTE_UNITS_PER_SECOND`] divided by the compute units a
+    /// single verify call consumes.
+    pub fn get_verify_throughput_capacity(&self) -> Result<f64, ClientError> {
+        let samples = self.rpc_client.get_recent_performance_samples(Some(1))?;
+        let sample = samples
+            .first()
+            .ok_or_else(|| ClientError::InvalidState("no recent performance samples".into()))?;
+        if sample.num_slots == 0 {
+            return Err(ClientError::InvalidState(
+                "performance sample covers zero slots".into(),
+            ));
+        }
+        let seconds_per_slot = sample.sample_period_secs as f64 / sample.num_slots as f64;
+        let avg_confirmation_time_seconds =
+            seconds_per_slot * APPROX_CONFIRMATION_SLOT_DEPTH as f64;
+        let confirmation_bound = 1.0 / avg_confirmation_time_seconds;
+
+        let units_consumed = self.get_compute_units_used_by_verify()?;
+        let compute_bound = if units_consumed == 0 {
+            f64::INFINITY
+        } else {
+            MAX_COMPUTE_UNITS_PER_SECOND as f64 / units_consumed as f64
+        };
+
+        Ok(confirmation_bound.min(compute_bound))
+    }
+
+    /// Runs a comprehensive consistency check over the full on-chain
+    /// verifier state and returns every issue found. An empty result means
+    /// the account is healthy.
+    pub fn validate_full_state(&self) -> Result<Vec<StateIssue>, ClientError> {
+        let account = self.get_verifier_account()?;
+        let mut issues = Vec::new();
+
+        if !account.configs.iter().any(|c| c.is_active) {
+            issues.push(StateIssue::new(
+                "no_active_config",
+                "verifier account has no active DON config",
+            ));
+        }
+
+        for config in &account.configs {
+            let required_signers = 3 * config.f as usize + 1;
+            if config.signers.len() < required_signers {
+                issues.push(StateIssue::new(
+                    "fault_tolerance_violated",
+                    format!(
+                        "config {:?} has f={} but only {} signers (needs >= {})",
+                        config.config_id,
+                        config.f,
+                        config.signers.len(),
+                        required_signers
+                    ),
+                ));
+            }
+        }
+
+        if account.access_controller == Pubkey::default() {
+            issues.push(StateIssue::new(
+                "access_controller_unset",
+                "verifier account has no access controller configured",
+            ));
+        }
+
+        let account_data_len = self
+            .rpc_client
+            .get_account(&self.verifier_data_account)?
+            .data
+            .len();
+        let expected_len = self.get_account_size_requirement();
+        if account_data_len != expected_len {
+            issues.push(StateIssue::new(
+                "account_size_mismatch",
+                format!(
+                    "account is {} bytes, expected {} bytes",
+                    account_data_len, expected_len
+                ),
+            ));
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        for config in &account.configs {
+            for signer in &config.signers {
+                if !seen.insert(*signer) {
+                    issues.push(StateIssue::new(
+                        "duplicate_signer",
+                        format!(
+                            "signer 0x{} appears in more than one config",
+                            hex::encode(signer)
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+
+    /// Fetches a finalized blockhash from the cluster and caches it for
+    /// subsequent [`Self::send_transaction`] calls.
+    pub fn force_refresh_blockhash(&self) -> Result<Hash, ClientError> {
+        let (blockhash, last_valid_block_height) = self
+            .rpc_client
+            .get_latest_blockhash_with_commitment(CommitmentConfig::finalized())?;
+        *self.cached_blockhash.lock().unwrap() = Some(CachedBlockhash {
+            blockhash,
+            last_valid_block_height,
+        });
+        Ok(blockhash)
+    }
+
+    /// Walks the program account's transaction history back to the earliest
+    /// confirmed signature, which corresponds to the program's initial
+    /// deployment, and returns the slot it landed in. Used for audit
+    /// logging.
+    pub fn get_program_deployment_slot(&self) -> Result<u64, ClientError> {
+        let mut before = None;
+        let mut earliest_slot = None;
+        loop {
+            let page = self.rpc_client.get_signatures_for_address_with_config(
+                &self.program_id,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    ..Default::default()
+                },
+            )?;
+            let Some(last) = page.last() else { break };
+            earliest_slot = Some(last.slot);
+            before = Some(last.signature.parse().map_err(|_| {
+                ClientError::Deserialize("invalid signature in transaction history".into())
+            })?);
+            if page.len() < 1000 {
+                break;
+            }
+        }
+        earliest_slot.ok_or_else(|| {
+            ClientError::InvalidState("no transaction history found for program".into())
+        })
+    }
+
+    /// Paginates through the program's transaction history back to
+    /// `since_slot`, decodes each transaction's verifier program
+    /// instructions, and returns every `SetConfig` instruction found, newest
+    /// first. Other config-related instructions (`SetConfigWithActivationTime`,
+    /// `RemoveLatestConfig`) are not included. Used for incremental audit log
+    /// generation.
+    pub fn get_config_history_since(
+        &self,
+        since_slot: u64,
+    ) -> Result<Vec<ConfigChangeRecord>, ClientError> {
+        let mut records = Vec::new();
+        let mut before = None;
+        loop {
+            let page = self.rpc_client.get_signatures_for_address_with_config(
+                &self.program_id,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    ..Default::default()
+                },
+            )?;
+            let Some(last) = page.last() else { break };
+            let reached_cutoff = last.slot <= since_slot;
+            before = Some(last.signature.parse().map_err(|_| {
+                ClientError::Deserialize("invalid signature in transaction history".into())
+            })?);
+
+            for entry in &page {
+                if entry.slot <= since_slot {
+                    continue;
+                }
+                let signature: Signature = entry.signature.parse().map_err(|_| {
+                    ClientError::Deserialize("invalid signature in transaction history".into())
+                })?;
+                let confirmed = self
+                    .rpc_client
+                    .get_transaction(&signature, UiTransactionEncoding::Base64)?;
+                let Some(transaction) = confirmed.transaction.transaction.decode() else {
+                    continue;
+                };
+                let account_keys = transaction.message.static_account_keys();
+                for instruction in transaction.message.instructions() {
+                    let Some(program_id) = account_keys.get(instruction.program_id_index as usize)
+                    else {
+                        continue;
+                    };
+                    if *program_id != self.program_id {
+                        continue;
+                    }
+                    let Ok(decoded) = VerifierInstruction::try_from_slice(&instruction.data) else {
+                        continue;
+                    };
+                    let instruction_type = match decoded {
+                        VerifierInstruction::SetConfig { .. } => ConfigInstruction::SetConfig,
+                        _ => continue,
+                    };
+                    records.push(ConfigChangeRecord {
+                        slot: entry.slot,
+                        instruction_type,
+                        signature,
+                    });
+                }
+            }
+
+            if reached_cutoff || page.len() < 1000 {
+                break;
+            }
+        }
+        Ok(records)
+    }
+
+    /// Walks the program's full transaction history and returns every
+    /// `TransferOwnership` and `AcceptOwnership` instruction found, newest
+    /// first.
+    pub fn get_ownership_history(&self) -> Result<Vec<OwnershipRecord>, ClientError> {
+        let mut records = Vec::new();
+        let mut before = None;
+        loop {
+            let page = self.rpc_client.get_signatures_for_address_with_config(
+                &self.program_id,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    ..Default::default()
+                },
+            )?;
+            let Some(last) = page.last() else { break };
+            before = Some(last.signature.parse().map_err(|_| {
+                ClientError::Deserialize("invalid signature in transaction history".into())
+            })?);
+
+            for entry in &page {
+                let signature: Signature = entry.signature.parse().map_err(|_| {
+                    ClientError::Deserialize("invalid signature in transaction history".into())
+                })?;
+                let confirmed = self
+                    .rpc_client
+                    .get_transaction(&signature, UiTransactionEncoding::Base64)?;
+                let Some(transaction) = confirmed.transaction.transaction.decode() else {
+                    continue;
+                };
+                let account_keys = transaction.message.static_account_keys();
+                for instruction in transaction.message.instructions() {
+                    let Some(program_id) = account_keys.get(instruction.program_id_index as usize)
+                    else {
+                        continue;
+                    };
+                    if *program_id != self.program_id {
+                        continue;
+                    }
+                    let Ok(decoded) = VerifierInstruction::try_from_slice(&instruction.data) else {
+                        continue;
+                    };
+                    let (instruction_type, new_owner) = match decoded {
+                        VerifierInstruction::TransferOwnership { new_owner } => {
+                            (OwnershipInstruction::TransferOwnership, new_owner)
+                        }
+                        VerifierInstruction::AcceptOwnership => {
+                            let Some(authority_index) = instruction.accounts.get(1) else {
+                                continue;
+                            };
+                            let Some(new_owner) = account_keys.get(*authority_index as usize)
+                            else {
+                                continue;
+                            };
+                            (OwnershipInstruction::AcceptOwnership, *new_owner)
+                        }
+                        _ => continue,
+                    };
+                    records.push(OwnershipRecord {
+                        slot: entry.slot,
+                        instruction_type,
+                        new_owner,
+                        signature,
+                    });
+                }
+            }
+
+            if page.len() < 1000 {
+                break;
+            }
+        }
+        Ok(records)
+    }
+
+    /// Returns how much longer the cached blockhash (fetching and caching
+    /// one first if necessary) remains valid for transaction submission.
+    pub fn get_blockhash_expiry_info(&self) -> Result<BlockhashExpiryInfo, ClientError> {
+        let (blockhash, last_valid_block_height) = {
+            let mut cached = self.cached_blockhash.lock().unwrap();
+            if cached.is_none() {
+                drop(cached);
+                self.force_refresh_blockhash()?;
+                cached = self.cached_blockhash.lock().unwrap();
+            }
+            let cached = cached.as_ref().expect("just populated above");
+            (cached.blockhash, cached.last_valid_block_height)
+        };
+        let current_height = self.rpc_client.get_block_height()?;
+        let slots_until_expiry = last_valid_block_height.saturating_sub(current_height);
+        Ok(BlockhashExpiryInfo {
+            blockhash,
+            slots_until_expiry,
+            seconds_until_expiry: slots_until_expiry as f64 * APPROX_SLOT_DURATION_MS as f64
+                / 1000.0,
+        })
+    }
+
+    /// Returns the cached blockhash if it has not yet expired, fetching and
+    /// caching a fresh one otherwise.
+    fn blockhash(&self) -> Result<Hash, ClientError> {
+        let current_height = self.rpc_client.get_block_height()?;
+        {
+            let cached = self.cached_blockhash.lock().unwrap();
+            if let Some(cached) = cached.as_ref() {
+                if current_height < cached.last_valid_block_height {
+                    return Ok(cached.blockhash);
+                }
+            }
+        }
+        self.force_refresh_blockhash()
+    }
+
+    /// Fetches the current durable nonce value stored in `nonce_account`.
+    fn durable_nonce_value(&self, nonce_account: &Pubkey) -> Result<Hash, ClientError> {
+        let data = self.rpc_client.get_account_data(nonce_account)?;
+        let versions: solana_nonce::versions::Versions = bincode::deserialize(&data)
+            .map_err(|e| ClientError::Deserialize(e.to_string()))?;
+        match versions.state() {
+            solana_nonce::state::State::Initialized(nonce_data) => Ok(nonce_data.blockhash()),
+            solana_nonce::state::State::Uninitialized => Err(ClientError::InvalidState(format!(
+                "nonce account {} is uninitialized",
+                nonce_account
+            ))),
+        }
+    }
+
+    /// Builds and signs a transaction containing `instructions`.
+    ///
+    /// If a nonce pool has been configured via [`Self::with_nonce_pool`],
+    /// pulls the next nonce round-robin and prepends an `AdvanceNonceAccount`
+    /// instruction. Otherwise reuses the cached blockhash when it is still
+    /// valid.
+    fn build_transaction(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Transaction, ClientError> {
+        let next_nonce_account = self
+            .nonce_pool
+            .lock()
+            .unwrap()
+            .as_mut()
+            .map(NoncePool::next_account);
+
+        let (blockhash, nonce_authority, all_instructions) = match next_nonce_account {
+            Some((nonce_pubkey, authority)) => {
+                let blockhash = self.durable_nonce_value(&nonce_pubkey)?;
+                let advance_ix = solana_system_interface::instruction::advance_nonce_account(
+                    &nonce_pubkey,
+                    &authority.pubkey(),
+                );
+                let mut all = vec![advance_ix];
+                all.extend_from_slice(instructions);
+                (blockhash, Some(authority), all)
+            }
+            None => (self.blockhash()?, None, instructions.to_vec()),
+        };
+
+        let mut all_signers: Vec<&Keypair> = vec![&self.payer];
+        if let Some(authority) = nonce_authority.as_ref() {
+            all_signers.push(authority);
+        }
+        all_signers.extend(signers);
+
+        Ok(Transaction::new_signed_with_payer(
+            &all_instructions,
+            Some(&self.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        ))
+    }
+
+    /// Builds, signs, and submits a transaction containing `instructions`,
+    /// waiting for confirmation.
+    pub fn send_transaction(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Signature, ClientError> {
+        let transaction = self.build_transaction(instructions, signers)?;
+        Ok(self.rpc_client.send_and_confirm_transaction(&transaction)?)
+    }
+
+    /// Submits a transaction with preflight checks enabled, and retries once
+    /// with preflight disabled if the first attempt fails. This works
+    /// around RPC nodes serving stale state during preflight simulation.
+    /// The error returned on total failure is from the non-preflight retry.
+    pub fn submit_transaction_with_preflight_fallback(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Signature, ClientError> {
+        let transaction = self.build_transaction(instructions, signers)?;
+
+        let with_preflight = RpcSendTransactionConfig {
+            skip_preflight: false,
+            ..RpcSendTransactionConfig::default()
+        };
+        if let Ok(signature) = self
+            .rpc_client
+            .send_transaction_with_config(&transaction, with_preflight)
+        {
+            return Ok(signature);
+        }
+
+        let without_preflight = RpcSendTransactionConfig {
+            skip_preflight: true,
+            ..RpcSendTransactionConfig::default()
+        };
+        Ok(self
+            .rpc_client
+            .send_transaction_with_config(&transaction, without_preflight)?)
+    }
+
+    /// Queries the Squads v4 multisig program for open proposals on
+    /// `multisig_address` whose instruction decodes as one this program
+    /// understands, i.e. proposals targeting the verifier program.
+    #[cfg(feature = "squads")]
+    pub fn list_open_proposals(
+        &self,
+        multisig_address: &Pubkey,
+    ) -> Result<Vec<ProposalSummary>, ClientError> {
+        let filters = vec![solana_client::rpc_filter::RpcFilterType::Memcmp(
+            solana_client::rpc_filter::Memcmp::new_base58_encoded(
+                8,
+                multisig_address.as_ref(),
+            ),
+        )];
+        let config = solana_client::rpc_config::RpcProgramAccountsConfig {
+            filters: Some(filters),
+            ..Default::default()
+        };
+        let accounts = self
+            .rpc_client
+            .get_program_accounts_with_config(&crate::squads::SQUADS_PROGRAM_ID, config)?;
+
+        let mut summaries = Vec::new();
+        for (proposal_address, account) in accounts {
+            let Some(data) = account.data.get(8..) else {
+                continue;
+            };
+            let Ok(proposal) = crate::squads::ProposalAccount::try_from_slice(data) else {
+                continue;
+            };
+            if proposal.multisig != *multisig_address || proposal.status != crate::squads::PROPOSAL_STATUS_OPEN
+            {
+                continue;
+            }
+            let Ok(decoded) = self.decode_instruction_data(&proposal.instruction_data) else {
+                continue;
+            };
+            summaries.push(ProposalSummary {
+                proposal_address,
+                instruction_name: decoded.name().to_string(),
+                created_at_slot: proposal.created_at_slot,
+                approvals_count: proposal.approved.len() as u32,
+            });
+        }
+        Ok(summaries)
+    }
+
+    /// Approves the Squads multisig proposal at `proposal_address`, signing
+    /// as `self.payer`.
+    #[cfg(feature = "squads")]
+    pub fn approve_proposal(&self, proposal_address: &Pubkey) -> Result<Signature, ClientError> {
+        let instruction = crate::squads::SquadsInstruction::Approve
+            .into_instruction(*proposal_address, self.payer.pubkey());
+        self.send_transaction(&[instruction], &[])
+    }
+
+    /// Executes the Squads multisig proposal at `proposal_address`, signing
+    /// as `self.payer`. The Squads program itself enforces that enough
+    /// approvals have been gathered before execution succeeds.
+    #[cfg(feature = "squads")]
+    pub fn execute_proposal(&self, proposal_address: &Pubkey) -> Result<Signature, ClientError> {
+        let instruction = crate::squads::SquadsInstruction::Execute
+            .into_instruction(*proposal_address, self.payer.pubkey());
+        self.send_transaction(&[instruction], &[])
+    }
+
+    /// Creates a Squads proposal on `multisig` wrapping a `SetConfig` call
+    /// for `signers`/`f`, and returns the new proposal's address. Use
+    /// [`Self::approve_proposal`] and [`Self::execute_proposal`] to carry
+    /// it to completion.
+    #[cfg(feature = "squads")]
+    pub fn create_squads_set_config_proposal(
+        &self,
+        multisig: &Pubkey,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+    ) -> Result<Pubkey, ClientError> {
+        self.check_signer_set_validity(&signers, f)?;
+        let config_id = Self::derive_config_id(&signers, f);
+        let proposal_account = Keypair::new();
+        let proposal_address = proposal_account.pubkey();
+        let instruction = crate::squads::create_set_config_proposal_instruction(
+            *multisig,
+            proposal_address,
+            self.payer.pubkey(),
+            config_id,
+            signers,
+            f,
+        );
+        self.send_transaction(&[instruction], &[&proposal_account])?;
+        Ok(proposal_address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_client() -> VerifierAdminClient {
+        VerifierAdminClient::new(
+            "http://localhost:8899",
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Keypair::new(),
+        )
+    }
+
+    #[test]
+    fn account_size_requirement_sizes_signers_at_20_bytes_each() {
+        let client = test_client();
+        let don_config_size = 32 + (4 + MAX_SIGNERS_PER_CONFIG * 20) + 1 + 1 + 8;
+        let expected = 32 + 1 + 32 + 4 + MAX_DON_CONFIGS * don_config_size;
+        assert_eq!(client.get_account_size_requirement(), expected);
+    }
+
+    #[test]
+    fn check_signer_set_validity_enforces_fault_tolerance_bound() {
+        let client = test_client();
+        let signers: Vec<[u8; 20]> = (0..4).map(|i| [i; 20]).collect();
+        assert!(client.check_signer_set_validity(&signers, 1).is_ok());
+        assert!(client.check_signer_set_validity(&signers[..3], 1).is_err());
+    }
+
+    #[test]
+    fn check_signer_set_validity_rejects_duplicates() {
+        let client = test_client();
+        let signers = vec![[1u8; 20], [1u8; 20], [2u8; 20], [3u8; 20]];
+        assert!(client.check_signer_set_validity(&signers, 0).is_err());
+    }
+
+    #[test]
+    fn derive_config_id_is_deterministic_and_content_sensitive() {
+        let signers_a = vec![[1u8; 20], [2u8; 20]];
+        let signers_b = vec![[2u8; 20], [1u8; 20]];
+
+        assert_eq!(
+            VerifierAdminClient::derive_config_id(&signers_a, 0),
+            VerifierAdminClient::derive_config_id(&signers_a, 0)
+        );
+        assert_ne!(
+            VerifierAdminClient::derive_config_id(&signers_a, 0),
+            VerifierAdminClient::derive_config_id(&signers_b, 0)
+        );
+        assert_ne!(
+            VerifierAdminClient::derive_config_id(&signers_a, 0),
+            VerifierAdminClient::derive_config_id(&signers_a, 1)
+        );
+    }
+
+    #[test]
+    fn config_age_has_reached_threshold_flags_configs_at_or_past_the_threshold() {
+        assert!(!config_age_has_reached_threshold(499, 500));
+        assert!(config_age_has_reached_threshold(500, 500));
+        assert!(config_age_has_reached_threshold(501, 500));
+    }
+}